@@ -17,6 +17,7 @@ extern crate xdg;
 #[macro_use]
 mod pazi_result;
 
+mod config;
 mod importers;
 mod matcher;
 mod frecency;
@@ -33,6 +34,86 @@ use shells::SUPPORTED_SHELLS;
 
 const PAZI_DB_NAME: &str = "pazi_dirs.msgpack";
 
+// Command (run via the shell) used to pick an interactive match when the
+// built-in selector is not wanted; the chosen line is read back from its stdout.
+const PAZI_SELECTOR_ENV: &str = "PAZI_FZF_CMD";
+
+// The prune window lives in the config file, but PAZI_PRUNE_AGE_DAYS still
+// overrides it (env takes precedence) so it can be tuned or disabled without
+// editing the config. Returns Some(override) when the variable is set to a
+// number of days, else None (an unparsable value is ignored with a warning);
+// the inner Option is None (0 days) to disable pruning entirely.
+const PAZI_PRUNE_AGE_ENV: &str = "PAZI_PRUNE_AGE_DAYS";
+
+fn prune_age_override() -> Option<Option<::std::time::Duration>> {
+    let raw = match env::var(PAZI_PRUNE_AGE_ENV) {
+        Ok(v) => v,
+        Err(_) => return None,
+    };
+    match raw.parse::<u64>() {
+        Ok(0) => Some(None),
+        Ok(days) => Some(Some(::std::time::Duration::from_secs(days * 24 * 60 * 60))),
+        Err(_) => {
+            warn!(
+                "{}: could not parse '{}' as a number of days; ignoring",
+                PAZI_PRUNE_AGE_ENV, raw
+            );
+            None
+        }
+    }
+}
+
+// run_selector drives an external interactive picker (e.g. fzf). The scored
+// matches are written to its stdin as `score<TAB>path`, one per line, and the
+// chosen line is read back from its stdout. A non-zero exit is taken to mean the
+// user aborted, in which case Ok(None) is returned.
+fn run_selector(cmd: &str, matches: &[(String, f64)]) -> std::io::Result<Option<String>> {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(cmd)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+
+    {
+        let stdin = child.stdin.as_mut().expect("selector stdin was piped");
+        for &(ref path, score) in matches {
+            // Highest-scoring entries are written last so they land at the bottom,
+            // matching the ordering of the built-in picker and the default listing.
+            if let Err(e) = writeln!(stdin, "{:.5}\t{}", score * 100f64, path) {
+                // fzf/sk close their stdin as soon as the user selects (common on
+                // long lists); a BrokenPipe here is expected, so stop feeding and
+                // go read the chosen line rather than discarding a valid selection.
+                if e.kind() == ::std::io::ErrorKind::BrokenPipe {
+                    break;
+                }
+                return Err(e);
+            }
+        }
+    }
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Ok(None);
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let chosen = stdout.lines().next().unwrap_or("").trim_right();
+    if chosen.is_empty() {
+        return Ok(None);
+    }
+    // Strip the score column we prefixed, tolerating selectors that echo the
+    // whole line back (fzf) as well as ones configured to print only a field.
+    let path = match chosen.rfind('\t') {
+        Some(idx) => &chosen[idx + 1..],
+        None => chosen,
+    };
+    Ok(Some(path.to_string()))
+}
+
 fn main() {
     let res = _main();
     let extended_exit_codes = match std::env::var(PAZI_EXTENDED_EXIT_CODES_ENV!()) {
@@ -70,11 +151,33 @@ fn _main() -> PaziResult {
         .subcommand(
             SubCommand::with_name("import")
                 .about("Import from another autojump program")
-                .usage("pazi import fasd")
+                .usage(format!("pazi import [ {} ]", importers::SUPPORTED_IMPORTERS.join(" | ")).as_str())
                 .arg(Arg::with_name("autojumper").help(&format!(
-                    "the other autojump program to import from, only fasd is currently supported",
+                    "the other autojump program to import from: one of {}",
+                    importers::SUPPORTED_IMPORTERS.join(", ")
                 ))),
         )
+        .subcommand(
+            SubCommand::with_name("edit")
+                .about("Inspect and curate entries in the frecency database")
+                .usage("pazi edit [ --remove <path> | --set-score <path> <value> ]")
+                .arg(
+                    Arg::with_name("remove")
+                        .help("remove an entry from the database")
+                        .long("remove")
+                        .takes_value(true)
+                        .value_name("path"),
+                )
+                .arg(
+                    Arg::with_name("set-score")
+                        .help("set an entry's frecency score to an explicit value")
+                        .long("set-score")
+                        .takes_value(true)
+                        .number_of_values(2)
+                        .value_names(&["path", "value"]),
+                )
+                .group(ArgGroup::with_name("edit_operation").args(&["remove", "set-score"])),
+        )
         .arg(
             Arg::with_name("dir")
                 .help(
@@ -90,6 +193,29 @@ fn _main() -> PaziResult {
                 .long("interactive")
                 .short("i"),
         )
+        .arg(
+            Arg::with_name("selector")
+                .help(
+                    "use an external command (e.g. fzf) to pick an interactive match instead of \
+                     the built-in selector; may also be set via PAZI_FZF_CMD",
+                )
+                .long("selector")
+                .takes_value(true)
+                .value_name("command")
+                .env(PAZI_SELECTOR_ENV),
+        )
+        .arg(
+            Arg::with_name("exclude")
+                .help(
+                    "exclude a path from directory matches; may be repeated. The 'z' function \
+                     'init' creates passes the current directory automatically",
+                )
+                .long("exclude")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .value_name("path"),
+        )
         .arg(
             Arg::with_name("add-dir")
                 .help("add a directory to the frecency index")
@@ -142,39 +268,85 @@ fn _main() -> PaziResult {
         .place_config_file(PAZI_DB_NAME)
         .expect(&format!("could not create xdg '{}' path", PAZI_DB_NAME));
 
-    let mut frecency = PathFrecency::load(&frecency_path);
+    let mut config = config::Config::load(&xdg_dirs);
+    if let Some(age) = prune_age_override() {
+        config.set_prune_age(age);
+    }
+
+    let mut frecency = PathFrecency::load(&frecency_path, &config);
 
     if let Some(import_matches) = flags.subcommand_matches("import") {
-        match import_matches.value_of("autojumper") {
-            Some("fasd") => match importers::Fasd::import(&mut frecency) {
-                Ok(stats) => match frecency.save_to_disk() {
-                    Ok(_) => {
-                        println!(
-                            "imported {} items from fasd (out of {} in its db)",
-                            stats.items_visited, stats.items_considered
-                        );
-                        return PaziResult::Success;
-                    }
-                    Err(e) => {
-                        println!("pazi: error adding directory: {}", e);
-                        return PaziResult::Error;
-                    }
-                },
+        let importer = match import_matches.value_of("autojumper") {
+            Some(name) => match importers::from_name(name) {
+                Some(i) => i,
+                None => {
+                    println!(
+                        "{}\n\nUnsupported import target: {}",
+                        import_matches.usage(),
+                        name
+                    );
+                    return PaziResult::Error;
+                }
+            },
+            None => {
+                println!("{}\n\nimport requires an argument", import_matches.usage());
+                return PaziResult::Error;
+            }
+        };
+        match importer.import(&mut frecency) {
+            Ok(stats) => match frecency.save_to_disk() {
+                Ok(_) => {
+                    println!(
+                        "imported {} items from {} (out of {} in its db)",
+                        stats.items_visited,
+                        importer.name(),
+                        stats.items_considered
+                    );
+                    return PaziResult::Success;
+                }
                 Err(e) => {
-                    println!("pazi: error importing from fasd: {}", e);
+                    println!("pazi: error adding directory: {}", e);
                     return PaziResult::Error;
                 }
             },
-            Some(s) => {
-                println!(
-                    "{}\n\nUnsupported import target: {}",
-                    import_matches.usage(),
-                    s
-                );
+            Err(e) => {
+                println!("pazi: error importing from {}: {}", importer.name(), e);
                 return PaziResult::Error;
             }
-            None => {
-                println!("{}\n\nimport requires an argument", import_matches.usage());
+        }
+    }
+
+    if let Some(edit_matches) = flags.subcommand_matches("edit") {
+        if let Some(path) = edit_matches.value_of("remove") {
+            if !frecency.remove(path) {
+                println!("pazi: no such entry: {}", path);
+                return PaziResult::Error;
+            }
+        } else if let Some(mut vals) = edit_matches.values_of("set-score") {
+            // Both values are guaranteed present by number_of_values(2).
+            let path = vals.next().unwrap();
+            let score = match vals.next().unwrap().parse::<f64>() {
+                Ok(s) => s,
+                Err(e) => {
+                    println!("pazi: could not parse score: {}", e);
+                    return PaziResult::Error;
+                }
+            };
+            frecency.set_score(path, score);
+        } else {
+            // No flags: drop into the interactive curation UI, reusing the same
+            // selector infrastructure as '--interactive'.
+            let stdout = termion::get_tty().unwrap();
+            if let Err(e) = interactive::edit(&mut frecency, std::io::stdin(), stdout) {
+                println!("{}", e);
+                return PaziResult::Error;
+            }
+        }
+
+        match frecency.save_to_disk() {
+            Ok(_) => return PaziResult::Success,
+            Err(e) => {
+                println!("pazi: error saving db changes: {}", e);
                 return PaziResult::Error;
             }
         }
@@ -182,6 +354,12 @@ fn _main() -> PaziResult {
 
     let res;
     if let Some(dir) = flags.value_of("add-dir") {
+        // Honour the config's exclusion patterns here too so shell glue that
+        // shells out to 'pazi --add-dir' never indexes an excluded path.
+        if config.is_excluded(dir) {
+            debug!("not indexing excluded path: {}", dir);
+            return PaziResult::Success;
+        }
         frecency.visit(dir.to_string());
 
         match frecency.save_to_disk() {
@@ -195,31 +373,70 @@ fn _main() -> PaziResult {
         }
     } else if flags.is_present("dir") {
         // Safe to unwrap because 'dir' requires 'dir_target'
-        let matches = match flags.value_of("dir_target") {
+        let excludes: Vec<String> = flags
+            .values_of("exclude")
+            .map(|vs| vs.map(|v| v.to_string()).collect())
+            .unwrap_or_default();
+
+        let mut matches = match flags.value_of("dir_target") {
             Some(to) => {
                 env::current_dir()
                     .map(|cwd| {
                         frecency.maybe_add_relative_to(cwd, to);
                     })
                     .unwrap_or(()); // truly ignore failure to get cwd
-                frecency.directory_matches(to)
+                frecency.directory_matches(to, &excludes)
             }
-            None => frecency.items_with_frecency(),
+            None => frecency.items_with_frecency(&excludes),
         };
+
+        // Never resolve to the directory we are already in: 'z foo' should move to a
+        // sibling rather than no-op when the current directory happens to be the top
+        // match. The 'z' wrapper also passes $PWD via --exclude, but fall through here
+        // too so an out-of-date wrapper still does the right thing.
+        if let Ok(cwd) = env::current_dir() {
+            // Compare on canonicalized paths so a stored entry that differs only by
+            // a trailing slash or a symlinked component still counts as "here".
+            // Canonicalization can fail for entries that no longer exist; fall back
+            // to the raw path (Path equality already ignores trailing separators).
+            let cwd = cwd.canonicalize().unwrap_or(cwd);
+            matches.retain(|m| {
+                let p = ::std::path::Path::new(&m.0);
+                let canon = p.canonicalize().unwrap_or_else(|_| p.to_path_buf());
+                canon != cwd
+            });
+        }
+
         if matches.len() == 0 {
             return PaziResult::Error;
         }
 
         if flags.is_present("interactive") {
-            let stdout = termion::get_tty().unwrap();
-            match interactive::filter(matches, std::io::stdin(), stdout) {
-                Ok(el) => {
-                    print!("{}", el);
-                    res = PaziResult::SuccessDirectory;
+            if let Some(cmd) = flags.value_of("selector") {
+                match run_selector(cmd, &matches) {
+                    Ok(Some(path)) => {
+                        print!("{}", path);
+                        res = PaziResult::SuccessDirectory;
+                    }
+                    // A non-zero exit / empty selection means the user aborted; treat
+                    // it like cancelling the built-in picker.
+                    Ok(None) => return PaziResult::Error,
+                    Err(e) => {
+                        println!("pazi: error running selector '{}': {}", cmd, e);
+                        return PaziResult::Error;
+                    }
                 }
-                Err(e) => {
-                    println!("{}", e);
-                    return PaziResult::Error;
+            } else {
+                let stdout = termion::get_tty().unwrap();
+                match interactive::filter(matches, std::io::stdin(), stdout) {
+                    Ok(el) => {
+                        print!("{}", el);
+                        res = PaziResult::SuccessDirectory;
+                    }
+                    Err(e) => {
+                        println!("{}", e);
+                        return PaziResult::Error;
+                    }
                 }
             }
         } else {
@@ -233,7 +450,7 @@ fn _main() -> PaziResult {
         return PaziResult::Error;
     } else {
         // By default print the frecency
-        for el in frecency.items_with_frecency() {
+        for el in frecency.items_with_frecency(&[]) {
             // precision for floats only handles the floating part, which leads to unaligned
             // output, e.g., for a precision value of '3', you might get:
             // 1.000
@@ -53,9 +53,21 @@ impl VTEData {
 
 impl vte::Perform for VTEData {
     fn print(&mut self, c: char) {
-        self.current_line.truncate(self.current_line_cursor);
+        // Overwrite the character under the cursor rather than truncating the tail,
+        // so prints interleaved with cursor-movement sequences (prompt redraws,
+        // right-prompts) behave like a real terminal. When the cursor sits at or
+        // past the end, the char is appended, after padding with spaces up to the cursor.
+        let mut chars: Vec<char> = self.current_line.chars().collect();
+        if self.current_line_cursor < chars.len() {
+            chars[self.current_line_cursor] = c;
+        } else {
+            while chars.len() < self.current_line_cursor {
+                chars.push(' ');
+            }
+            chars.push(c);
+        }
+        self.current_line = chars.into_iter().collect();
         self.current_line_cursor += 1;
-        self.current_line.push(c);
     }
 
     fn execute(&mut self, byte: u8) {
@@ -99,8 +111,56 @@ impl vte::Perform for VTEData {
         // ignore
     }
 
-    fn csi_dispatch(&mut self, _: &[i64], _: &[u8], _: bool, _: char) {
-        // ignore
+    fn csi_dispatch(&mut self, params: &[i64], _: &[u8], _: bool, action: char) {
+        // Cursor movement sequences default a missing or zero parameter to 1.
+        let movement = |idx: usize| -> usize {
+            match params.get(idx).cloned() {
+                Some(v) if v > 0 => v as usize,
+                _ => 1,
+            }
+        };
+        // current_line_cursor is a char index (see print), so every handler works
+        // in char units and clamps to the char count; indexing bytes here would
+        // panic on the multibyte prompt glyphs (fish's ❯, powerline) this is for.
+        let len = self.current_line.chars().count();
+        match action {
+            // CUF / CUB: move the cursor right / left, clamped to the line bounds.
+            'C' => {
+                self.current_line_cursor = (self.current_line_cursor + movement(0)).min(len);
+            }
+            'D' => {
+                self.current_line_cursor = self.current_line_cursor.saturating_sub(movement(0));
+            }
+            // CHA: move to an absolute, 1-based column.
+            'G' => {
+                let col = params.get(0).cloned().unwrap_or(1);
+                let col = if col > 0 { (col - 1) as usize } else { 0 };
+                self.current_line_cursor = col.min(len);
+            }
+            // EL: erase in line. Unlike the movement sequences, 0 is meaningful here.
+            'K' => {
+                let chars: Vec<char> = self.current_line.chars().collect();
+                let cursor = self.current_line_cursor.min(chars.len());
+                match params.get(0).cloned().unwrap_or(0) {
+                    // to end of line
+                    0 => self.current_line = chars[..cursor].iter().collect(),
+                    // to start of line: blank out everything up to the cursor
+                    1 => {
+                        let mut out: String =
+                            ::std::iter::repeat(' ').take(cursor).collect();
+                        out.extend(chars[cursor..].iter());
+                        self.current_line = out;
+                    }
+                    // the whole line
+                    2 => {
+                        self.current_line.truncate(0);
+                        self.current_line_cursor = 0;
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
     }
 
     fn esc_dispatch(&mut self, _: &[i64], _: &[u8], _: bool, _: u8) {